@@ -1,4 +1,4 @@
-use std::io;
+use std::io::{self, IsTerminal};
 
 use rand::prelude::*;
 use wordle_lib::Guess;
@@ -39,9 +39,14 @@ fn main() {
 fn play_game(answer: &Guess) -> GameVictory {
     for i in 0..6 {
         let user_guess = get_any_guess();
-        let resp = user_guess.verify(&answer);
+        let resp = user_guess.verify(answer);
 
-        println!("{}", resp.unpretty_string());
+        // Only color when writing to a terminal; piped output stays plain.
+        if io::stdout().is_terminal() {
+            println!("{}", resp.colored_string(&user_guess));
+        } else {
+            println!("{}", resp.unpretty_string());
+        }
         println!("{} guesses left!", 5 - i);
 
         if resp.victory() {
@@ -0,0 +1,132 @@
+//! Measures how well a [`Solver`] plays over the whole dictionary.
+//!
+//! [`run`] replays a full six-guess game for every word in the dictionary as
+//! the hidden answer and reports aggregate statistics, giving a reproducible
+//! way to compare solvers and evaluate opening words. Each game is independent,
+//! so the simulations are fanned out across threads with rayon.
+
+use rayon::prelude::*;
+
+use crate::solver::Solver;
+use crate::Guess;
+
+/// Aggregate statistics gathered over a whole-dictionary run.
+pub struct BenchReport {
+    /// Total games played (one per dictionary word).
+    pub games: usize,
+    /// `histogram[n]` is how many games were solved in `n` guesses for
+    /// `1..=6`; `histogram[0]` counts games that were never solved.
+    pub histogram: [usize; 7],
+    /// Words the solver solved on the last guess or failed outright.
+    pub worst: Vec<Guess>,
+}
+
+impl BenchReport {
+    /// Games that ended in a win (solved within six guesses).
+    #[must_use]
+    pub fn wins(&self) -> usize {
+        self.histogram[1..].iter().sum()
+    }
+
+    /// Fraction of games won, in `0.0..=1.0`.
+    #[must_use]
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            return 0.0;
+        }
+        self.wins() as f64 / self.games as f64
+    }
+
+    /// Mean number of guesses across the games that were won.
+    #[must_use]
+    pub fn average_guesses(&self) -> f64 {
+        let wins = self.wins();
+        if wins == 0 {
+            return 0.0;
+        }
+        let total: usize = (1..=6).map(|n| n * self.histogram[n]).sum();
+        total as f64 / wins as f64
+    }
+}
+
+/// Per-thread tally, merged into the final [`BenchReport`] by [`run`].
+#[derive(Default)]
+struct Tally {
+    histogram: [usize; 7],
+    worst: Vec<Guess>,
+}
+
+/// Runs `solver` against every word in `dict` as the hidden answer.
+///
+/// Simulates a full six-guess game per answer via [`Guess::verify`],
+/// accumulating a histogram per thread and merging the partial tallies.
+#[must_use]
+pub fn run<S: Solver + Sync>(solver: &S, dict: &[Guess]) -> BenchReport {
+    let tally = dict
+        .par_iter()
+        .fold(Tally::default, |mut tally, answer| {
+            match simulate(solver, dict, answer) {
+                Some(n) => {
+                    tally.histogram[n as usize] += 1;
+                    if n == 6 {
+                        tally.worst.push(answer.clone());
+                    }
+                }
+                None => {
+                    tally.histogram[0] += 1;
+                    tally.worst.push(answer.clone());
+                }
+            }
+            tally
+        })
+        .reduce(Tally::default, |mut acc, part| {
+            acc.histogram
+                .iter_mut()
+                .zip(part.histogram)
+                .for_each(|(slot, count)| *slot += count);
+            acc.worst.extend(part.worst);
+            acc
+        });
+
+    BenchReport {
+        games: dict.len(),
+        histogram: tally.histogram,
+        worst: tally.worst,
+    }
+}
+
+/// Plays one game and returns the guess count on a win, or `None` on a loss.
+fn simulate<S: Solver>(solver: &S, dict: &[Guess], answer: &Guess) -> Option<u8> {
+    let mut candidates = dict.to_vec();
+    for turn in 1..=6u8 {
+        let guess = solver.next_guess(&candidates, dict)?;
+        let resp = guess.verify(answer);
+        if resp.victory() {
+            return Some(turn);
+        }
+        candidates.retain(|c| guess.verify(c) == resp);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::Naive;
+
+    fn words(list: &[&str]) -> Vec<Guess> {
+        list.iter()
+            .map(|w| Guess::build((*w).to_owned()).expect("values are hardcoded, shouldn't fail"))
+            .collect()
+    }
+
+    #[test]
+    fn naive_wins_a_small_dictionary() {
+        let dict = words(&["crane", "slate", "speed", "steal"]);
+        let report = run(&Naive, &dict);
+
+        assert_eq!(report.games, dict.len());
+        assert_eq!(report.wins(), dict.len());
+        assert!((report.win_rate() - 1.0).abs() < f64::EPSILON);
+    }
+}
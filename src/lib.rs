@@ -1,6 +1,9 @@
 #![allow(dead_code)]
 use std::fmt;
 
+pub mod bench;
+pub mod solver;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Guess {
     text: String,
@@ -14,15 +17,16 @@ const GRAY: char = '⬜';
 pub enum GuessError {
     NotFiveLetters,
     NotAlphabetic,
+    HardMode(String),
 }
 
 impl fmt::Display for GuessError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let error_text = match *self {
-            Self::NotFiveLetters => "wasn't given 5 letters exactly!",
-            Self::NotAlphabetic => "wasn't given alphabetic string!",
-        };
-        write!(f, "{error_text}")
+        match self {
+            Self::NotFiveLetters => write!(f, "wasn't given 5 letters exactly!"),
+            Self::NotAlphabetic => write!(f, "wasn't given alphabetic string!"),
+            Self::HardMode(why) => write!(f, "doesn't respect hard mode: {why}"),
+        }
     }
 }
 
@@ -67,6 +71,33 @@ impl Guess {
         Self { text }
     }
 
+    /// Like [`build`](Self::build) but also requires the guess to respect every
+    /// clue already revealed by `history`, i.e. Wordle's "hard mode".
+    ///
+    /// # Errors
+    /// Returns the same errors as [`build`](Self::build), plus
+    /// [`GuessError::HardMode`] if the guess drops a known letter or reuses a
+    /// letter proven absent (see [`validate_hard`] for the exact rules).
+    ///
+    /// # Examples
+    /// ```
+    /// let crane = wordle_lib::Guess::build("crane".to_owned()).unwrap();
+    /// let answer = wordle_lib::Guess::build("raven".to_owned()).unwrap();
+    /// let history = [(crane.clone(), crane.verify(&answer))];
+    ///
+    /// // "raven" keeps the revealed r/a/n/e, "salts" drops r, n and e.
+    /// assert!(wordle_lib::Guess::build_hard("raven".to_owned(), &history).is_ok());
+    /// assert!(wordle_lib::Guess::build_hard("salts".to_owned(), &history).is_err());
+    /// ```
+    pub fn build_hard(
+        text: String,
+        history: &[(Guess, GameResponse)],
+    ) -> Result<Self, GuessError> {
+        let guess = Self::build(text)?;
+        validate_hard(&guess, history)?;
+        Ok(guess)
+    }
+
     fn as_array(&self) -> [char; 5] {
         let mut build_array: [char; 5] = ['a', 'a', 'a', 'a', 'a'];
         for (i, c) in self.text.chars().enumerate() {
@@ -117,6 +148,94 @@ impl Guess {
         }
         GameResponse::new_from_game_resp_char(resp)
     }
+
+    /// Packs the five letters into a [`PackedWord`] for fast repeated scoring.
+    ///
+    /// Build this once from a `Guess` and hand it to [`verify_packed`] so a
+    /// solver or benchmark can score a whole dictionary without re-parsing the
+    /// backing string.
+    ///
+    /// [`verify_packed`]: Guess::verify_packed
+    #[must_use]
+    pub fn packed(&self) -> PackedWord {
+        let mut bits = 0u64;
+        for (i, b) in self.text.bytes().take(5).enumerate() {
+            bits |= u64::from(b) << (i * 8);
+        }
+        PackedWord(bits)
+    }
+
+    /// Allocation-free [`verify`] against a pre-packed answer.
+    ///
+    /// Reproduces the same two-pass duplicate handling as [`verify`] but in
+    /// `O(5)` with a stack-allocated letter-count array, so answers can be
+    /// packed once and scored tightly in a loop.
+    ///
+    /// [`verify`]: Guess::verify
+    ///
+    /// # Examples
+    /// ```
+    /// let guess = wordle_lib::Guess::build("speed".to_owned()).unwrap();
+    /// let answer = wordle_lib::Guess::build("erase".to_owned()).unwrap();
+    /// assert_eq!(
+    ///     guess.verify_packed(answer.packed()).to_string(),
+    ///     guess.verify(&answer).to_string(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn verify_packed(&self, answer: PackedWord) -> GameResponse {
+        let guess = self.packed().bytes();
+        let answer = answer.bytes();
+        let mut resp = GameResponseChar::five_greys();
+        // Index by the raw byte so upper-case or other non-`a..=z` letters
+        // (which `build` still accepts) compare exactly like they do in
+        // `verify`, instead of overflowing a 26-slot table.
+        let mut counts = [0u8; 256];
+        for &b in &answer {
+            counts[b as usize] += 1;
+        }
+
+        // First pass: greens consume their letter from the count.
+        for (i, (&g, &a)) in guess.iter().zip(answer.iter()).enumerate() {
+            if g == a {
+                resp[i] = GameResponseChar::Green;
+                counts[g as usize] -= 1;
+            }
+        }
+
+        // Second pass: a letter is yellow only while copies of it remain.
+        for (i, &g) in guess.iter().enumerate() {
+            if resp[i] == GameResponseChar::Green {
+                continue;
+            }
+            if counts[g as usize] > 0 {
+                resp[i] = GameResponseChar::Yellow;
+                counts[g as usize] -= 1;
+            }
+        }
+
+        GameResponse::new_from_game_resp_char(resp)
+    }
+}
+
+/// A five-letter word packed into a `u64`, one byte per letter (`b'a'..=b'z'`
+/// for a lower-case word; other bytes accepted by `build` are stored as-is).
+///
+/// Built from a [`Guess`] with [`Guess::packed`] and consumed by
+/// [`Guess::verify_packed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedWord(u64);
+
+impl PackedWord {
+    const fn bytes(self) -> [u8; 5] {
+        [
+            (self.0 & 0xff) as u8,
+            ((self.0 >> 8) & 0xff) as u8,
+            ((self.0 >> 16) & 0xff) as u8,
+            ((self.0 >> 24) & 0xff) as u8,
+            ((self.0 >> 32) & 0xff) as u8,
+        ]
+    }
 }
 
 impl fmt::Display for Guess {
@@ -125,7 +244,85 @@ impl fmt::Display for Guess {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+/// Checks `guess` against the clues revealed in `history`, Wordle hard-mode style.
+///
+/// For every `(prev, resp)` already played: each `Green` position must keep the
+/// same letter, each `Yellow` letter must reappear somewhere at a different
+/// position, and a letter shown to be absent (or capped by a `Gray`) must not
+/// occur in the new guess more often than it was proven to exist.
+///
+/// # Errors
+/// Returns [`GuessError::HardMode`] naming the first violated constraint.
+pub fn validate_hard(guess: &Guess, history: &[(Guess, GameResponse)]) -> Result<(), GuessError> {
+    let guessed = guess.as_array();
+
+    for (prev, resp) in history {
+        let played = prev.as_array();
+
+        // Every Green position must keep its revealed letter, and a Yellow
+        // letter can't stay at the position it was already shown not to be.
+        for (i, resp_char) in resp.text.iter().enumerate() {
+            if *resp_char == GameResponseChar::Green && guessed[i] != played[i] {
+                return Err(GuessError::HardMode(format!(
+                    "letter {} must be '{}'",
+                    i + 1,
+                    played[i]
+                )));
+            }
+            if *resp_char == GameResponseChar::Yellow && guessed[i] == played[i] {
+                return Err(GuessError::HardMode(format!(
+                    "letter {} can't be '{}'",
+                    i + 1,
+                    played[i]
+                )));
+            }
+        }
+
+        // Every letter revealed (Green or Yellow) must appear at least as many
+        // times in the new guess as it was revealed, not merely once.
+        for (i, resp_char) in resp.text.iter().enumerate() {
+            if *resp_char == GameResponseChar::Gray {
+                continue;
+            }
+            let letter = played[i];
+            let required = played
+                .iter()
+                .zip(resp.text.iter())
+                .filter(|(c, r)| **c == letter && **r != GameResponseChar::Gray)
+                .count();
+            let used = guessed.iter().filter(|c| **c == letter).count();
+            if used < required {
+                return Err(GuessError::HardMode(format!(
+                    "guess must contain at least {required} '{letter}'"
+                )));
+            }
+        }
+
+        // A `Gray` caps how many copies of that letter the answer can hold: the
+        // number of times the same letter was Green or Yellow in the played guess.
+        for (i, resp_char) in resp.text.iter().enumerate() {
+            if *resp_char != GameResponseChar::Gray {
+                continue;
+            }
+            let letter = played[i];
+            let allowed = played
+                .iter()
+                .zip(resp.text.iter())
+                .filter(|(c, r)| **c == letter && **r != GameResponseChar::Gray)
+                .count();
+            let used = guessed.iter().filter(|c| **c == letter).count();
+            if used > allowed {
+                return Err(GuessError::HardMode(format!(
+                    "letter '{letter}' can't appear {used} time(s)"
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 enum GameResponseChar {
     Green,
     Yellow,
@@ -149,22 +346,49 @@ impl GameResponseChar {
         }
     }
 
+    /// ANSI SGR prefix colouring the letter the way the web game does.
+    const fn to_ansi(&self) -> &'static str {
+        match *self {
+            Self::Green => "\x1b[30;42m",
+            Self::Yellow => "\x1b[30;43m",
+            Self::Gray => "\x1b[37;100m",
+        }
+    }
+
     const fn five_greys() -> [Self; 5] {
         [Self::Gray, Self::Gray, Self::Gray, Self::Gray, Self::Gray]
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct GameResponse {
     text: [GameResponseChar; 5],
 }
 
+#[derive(Debug)]
+pub enum GameResponseError {
+    NotFiveLetters,
+    UnknownChar(char),
+}
+
+impl fmt::Display for GameResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::NotFiveLetters => write!(f, "wasn't given 5 letters exactly!"),
+            Self::UnknownChar(c) => write!(f, "contains '{c}', which isn't G, Y, X or -!"),
+        }
+    }
+}
+
 impl GameResponse {
     /// - for Grey, C for Green, Y for Yellow.
     /// # Panics
     /// Will panic if string contains any characters that aren't G, Y, X or -.
+    /// Prefer [`from_encoded`](Self::from_encoded) on any input path where the
+    /// text isn't hardcoded, so a bad character is reported instead of panicking.
     #[allow(clippy::needless_pass_by_value)]
     #[must_use]
-    fn new(text: String) -> Self {
+    pub fn new(text: String) -> Self {
         let mut my_array: [GameResponseChar; 5] = GameResponseChar::five_greys();
         for (i, c) in text.chars().enumerate() {
             my_array[i] = match c {
@@ -179,6 +403,39 @@ impl GameResponse {
         Self { text: my_array }
     }
 
+    /// Parses a response from the `G`/`Y`/`-` (or `X`) encoding a player copies
+    /// off an external board, pairing it with the guess word they played.
+    ///
+    /// Unlike [`new`](Self::new) this reports a bad input instead of panicking,
+    /// so it is safe to call on user-supplied text.
+    ///
+    /// # Errors
+    /// Returns [`GameResponseError::NotFiveLetters`] if the string isn't exactly
+    /// five characters, or [`GameResponseError::UnknownChar`] if it contains a
+    /// character other than `G`, `Y`, `X` or `-`.
+    ///
+    /// # Examples
+    /// ```
+    /// let resp = wordle_lib::GameResponse::from_encoded("-Y-G-").unwrap();
+    /// assert_eq!(resp.to_string(), "-Y-G-");
+    /// assert!(wordle_lib::GameResponse::from_encoded("nope!").is_err());
+    /// ```
+    pub fn from_encoded(text: &str) -> Result<Self, GameResponseError> {
+        if text.chars().count() != 5 {
+            return Err(GameResponseError::NotFiveLetters);
+        }
+        let mut my_array = GameResponseChar::five_greys();
+        for (i, c) in text.chars().enumerate() {
+            my_array[i] = match c {
+                'G' => GameResponseChar::Green,
+                'Y' => GameResponseChar::Yellow,
+                'X' | '-' => GameResponseChar::Gray,
+                other => return Err(GameResponseError::UnknownChar(other)),
+            };
+        }
+        Ok(Self { text: my_array })
+    }
+
     /// Returns string of G, Y, and -'s.
     pub fn unpretty_string(&self) -> String {
         self.text.iter().map(GameResponseChar::to_char).collect()
@@ -188,6 +445,25 @@ impl GameResponse {
         self.text.iter().map(GameResponseChar::to_emoji).collect()
     }
 
+    /// Renders `guess`'s letters color-coded by this response, the way the web
+    /// game shows them: green, yellow, or gray backgrounds.
+    ///
+    /// Always emits ANSI escapes; it is up to the caller to fall back to
+    /// [`unpretty_string`](Self::unpretty_string) when the target isn't a TTY
+    /// (see `examples/term.rs`).
+    #[must_use]
+    pub fn colored_string(&self, guess: &Guess) -> String {
+        const RESET: &str = "\x1b[0m";
+        let letters = guess.as_array();
+        let mut out = String::new();
+        for (resp_char, letter) in self.text.iter().zip(letters.iter()) {
+            out.push_str(resp_char.to_ansi());
+            out.push(*letter);
+            out.push_str(RESET);
+        }
+        out
+    }
+
     #[must_use]
     pub fn victory(&self) -> bool {
         self.text.iter().all(|x| *x == GameResponseChar::Green)
@@ -248,6 +524,36 @@ mod tests {
     test_gameresp!(speed_abide: "abide", "--Y-Y");
     test_gameresp!(speed_steal: "steal", "G-G--");
 
+    macro_rules! test_gameresp_packed {
+        ($name_of_function:ident: $answer:expr, $result:expr) => {
+            #[test]
+            fn $name_of_function() {
+                let guess =
+                    Guess::build("speed".to_string()).expect("value is hardcoded, shouldn't fail");
+                let answer =
+                    Guess::build($answer.to_string()).expect("value is hardcoded, shouldn't fail");
+                let resp: GameResponse = guess.verify_packed(answer.packed());
+                assert_eq!(resp.to_string(), $result);
+            }
+        };
+    }
+
+    test_gameresp_packed!(packed_speed_speed: "speed", "GGGGG");
+    test_gameresp_packed!(packed_speed_crepe: "crepe", "-YGY-");
+    test_gameresp_packed!(packed_speed_erase: "erase", "Y-YY-");
+    test_gameresp_packed!(packed_speed_abide: "abide", "--Y-Y");
+    test_gameresp_packed!(packed_speed_steal: "steal", "G-G--");
+
+    #[test]
+    fn verify_packed_matches_verify_for_uppercase() {
+        let guess = Guess::build("CRANE".to_string()).expect("build accepts upper-case");
+        let answer = Guess::build("crane".to_string()).expect("value is hardcoded, shouldn't fail");
+        assert_eq!(
+            guess.verify_packed(answer.packed()).to_string(),
+            guess.verify(&answer).to_string(),
+        );
+    }
+
     #[test]
     fn verify_response() {
         let guess = Guess::build("speed".to_string()).expect("value is hardcoded, shouldn't fail");
@@ -278,4 +584,59 @@ mod tests {
         let resp = GameResponse::new("GYGAX".to_string());
         resp.pretty_string();
     }
+
+    #[test]
+    fn from_encoded_roundtrips() {
+        let resp = GameResponse::from_encoded("-Y-G-").expect("valid encoding");
+        assert_eq!(resp.to_string(), "-Y-G-");
+    }
+
+    #[test]
+    fn from_encoded_rejects_bad_char() {
+        let err = GameResponse::from_encoded("GYGAX").expect_err("A isn't a valid char");
+        assert!(matches!(err, GameResponseError::UnknownChar('A')));
+    }
+
+    #[test]
+    fn from_encoded_rejects_wrong_length() {
+        let err = GameResponse::from_encoded("GY").expect_err("too short");
+        assert!(matches!(err, GameResponseError::NotFiveLetters));
+    }
+
+    fn history(guess: &str, answer: &str) -> Vec<(Guess, GameResponse)> {
+        let guess = Guess::build(guess.to_owned()).expect("hardcoded, shouldn't fail");
+        let answer = Guess::build(answer.to_owned()).expect("hardcoded, shouldn't fail");
+        let resp = guess.verify(&answer);
+        vec![(guess, resp)]
+    }
+
+    #[test]
+    fn hard_mode_accepts_consistent_guess() {
+        let past = history("crane", "raven");
+        assert!(Guess::build_hard("raven".to_owned(), &past).is_ok());
+    }
+
+    #[test]
+    fn hard_mode_rejects_dropped_yellow() {
+        let past = history("crane", "raven");
+        let err = Guess::build_hard("salts".to_owned(), &past).expect_err("drops r/n/e");
+        assert!(matches!(err, GuessError::HardMode(_)));
+    }
+
+    #[test]
+    fn hard_mode_requires_green_position() {
+        let past = history("speed", "steal");
+        let err = Guess::build_hard("crane".to_owned(), &past).expect_err("loses green s");
+        assert!(matches!(err, GuessError::HardMode(_)));
+    }
+
+    #[test]
+    fn hard_mode_enforces_yellow_multiplicity() {
+        // "eerie" vs "speed" yields "YY---": two e's revealed, so a guess with
+        // only one e must be rejected, while two e's pass.
+        let past = history("eerie", "speed");
+        let err = Guess::build_hard("steal".to_owned(), &past).expect_err("only one e");
+        assert!(matches!(err, GuessError::HardMode(_)));
+        assert!(Guess::build_hard("bleep".to_owned(), &past).is_ok());
+    }
 }
@@ -0,0 +1,162 @@
+//! Turns the crate from a game into an analysis tool.
+//!
+//! Given a word list and the history of `(Guess, GameResponse)` pairs played so
+//! far, a [`Solver`] recommends the next guess to play. Everything is built on
+//! top of [`Guess::verify`], so the duplicate-letter handling matches what the
+//! game itself produces.
+
+use std::collections::HashMap;
+
+use crate::{GameResponse, Guess};
+
+/// Retains only the words still consistent with everything played so far.
+///
+/// A word `a` survives when, for every `(guess, resp)` in `history`,
+/// `guess.verify(a)` reproduces the observed `resp` exactly.
+///
+/// # Examples
+/// ```
+/// use wordle_lib::Guess;
+/// use wordle_lib::solver::remaining_candidates;
+///
+/// let words = ["crane", "slate", "speed"]
+///     .iter()
+///     .map(|w| Guess::build((*w).to_owned()).unwrap())
+///     .collect::<Vec<_>>();
+/// let guess = Guess::build("speed".to_owned()).unwrap();
+/// let answer = Guess::build("speed".to_owned()).unwrap();
+/// let history = [(guess.clone(), guess.verify(&answer))];
+///
+/// let left = remaining_candidates(&words, &history);
+/// assert_eq!(left, vec![answer]);
+/// ```
+#[must_use]
+pub fn remaining_candidates(words: &[Guess], history: &[(Guess, GameResponse)]) -> Vec<Guess> {
+    words
+        .iter()
+        .filter(|candidate| {
+            history
+                .iter()
+                .all(|(guess, resp)| guess.verify(candidate) == *resp)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Recommends the next guess to play.
+pub trait Solver {
+    /// Picks a guess given the words still possible (`candidates`) and the full
+    /// list of words allowed to be guessed (`allowed`).
+    ///
+    /// Returns `None` only when there is nothing left to suggest.
+    fn next_guess(&self, candidates: &[Guess], allowed: &[Guess]) -> Option<Guess>;
+}
+
+/// Suggests any remaining candidate, without looking any further ahead.
+pub struct Naive;
+
+impl Solver for Naive {
+    fn next_guess(&self, candidates: &[Guess], _allowed: &[Guess]) -> Option<Guess> {
+        candidates.first().cloned()
+    }
+}
+
+/// Picks the guess whose responses split the candidate set most evenly.
+///
+/// For each allowed guess `g` the candidates are partitioned by the response
+/// pattern `g.verify(candidate)` produces, and `g` is scored by the Shannon
+/// entropy `H(g) = -Σ pᵢ log₂ pᵢ` of that partition. The guess with the highest
+/// entropy wins; ties are broken in favour of a guess that is itself still a
+/// possible answer.
+pub struct Entropy;
+
+impl Entropy {
+    fn entropy(guess: &Guess, candidates: &[Guess]) -> f64 {
+        let mut buckets: HashMap<GameResponse, u32> = HashMap::new();
+        for candidate in candidates {
+            *buckets.entry(guess.verify(candidate)).or_insert(0) += 1;
+        }
+
+        let total = candidates.len() as f64;
+        buckets
+            .values()
+            .map(|&count| {
+                let p = f64::from(count) / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+impl Solver for Entropy {
+    fn next_guess(&self, candidates: &[Guess], allowed: &[Guess]) -> Option<Guess> {
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let mut best: Option<(Guess, f64, bool)> = None;
+        for guess in allowed {
+            let score = Self::entropy(guess, candidates);
+            let possible = candidates.contains(guess);
+            let better = match best {
+                None => true,
+                Some((_, best_score, best_possible)) => {
+                    score > best_score + f64::EPSILON
+                        || ((score - best_score).abs() <= f64::EPSILON
+                            && possible
+                            && !best_possible)
+                }
+            };
+            if better {
+                best = Some((guess.clone(), score, possible));
+            }
+        }
+
+        best.map(|(guess, _, _)| guess)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[&str]) -> Vec<Guess> {
+        list.iter()
+            .map(|w| Guess::build((*w).to_owned()).expect("values are hardcoded, shouldn't fail"))
+            .collect()
+    }
+
+    #[test]
+    fn filters_down_to_the_answer() {
+        let dict = words(&["crane", "slate", "speed", "steal"]);
+        let guess = Guess::build("speed".to_owned()).unwrap();
+        let answer = Guess::build("speed".to_owned()).unwrap();
+        let history = [(guess.clone(), guess.verify(&answer))];
+
+        assert_eq!(remaining_candidates(&dict, &history), vec![answer]);
+    }
+
+    #[test]
+    fn naive_returns_a_candidate() {
+        let dict = words(&["crane", "slate"]);
+        let pick = Naive.next_guess(&dict, &dict).unwrap();
+        assert!(dict.contains(&pick));
+    }
+
+    #[test]
+    fn entropy_prefers_the_most_discriminating_guess() {
+        // "crane" gives a distinct pattern for every candidate (five buckets),
+        // while "fuzzy" barely touches them (only "lucky" splits off; the other
+        // four share one bucket), so entropy must pick "crane".
+        let candidates = words(&["crane", "slate", "point", "lucky", "mirth"]);
+        let allowed = words(&["crane", "fuzzy"]);
+        let pick = Entropy.next_guess(&candidates, &allowed).unwrap();
+        assert_eq!(pick.to_string(), "crane");
+    }
+
+    #[test]
+    fn entropy_is_none_without_candidates() {
+        let allowed = words(&["crane"]);
+        assert!(Entropy.next_guess(&[], &allowed).is_none());
+    }
+}